@@ -5,8 +5,9 @@ use clippy_utils::visitors::{for_each_expr, is_local_used};
 use rustc_ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
-use rustc_hir::{Arm, BinOpKind, Expr, ExprKind, Guard, MatchSource, Node, Pat, PatKind};
+use rustc_hir::{Arm, BinOpKind, Expr, ExprKind, Guard, HirId, MatchSource, Pat, PatKind, UnOp};
 use rustc_lint::LateContext;
+use rustc_middle::ty::TyKind;
 use rustc_span::Span;
 use std::ops::ControlFlow;
 
@@ -18,44 +19,59 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'tcx>]) {
             continue;
         };
 
-        // `Some(x) if matches!(x, y)`
+        // `Some(x) if matches!(x, y)`, `Some(x) if matches!(x, A | B)`, or
+        // `Some(x) if matches!(x, y if z)`. `matches!` itself only ever expands to a single
+        // non-wildcard arm (whose pattern may already be an or-pattern like `A | B`), but a guard
+        // can also be written as a raw `match` expression with several non-wildcard arms, e.g.
+        // `Some(x) if match x { 1 => true, 2 => true, _ => false }`; folding those into one
+        // `|`-joined pattern below handles that hand-written form too.
         if let Guard::If(if_expr) = guard
-            && let ExprKind::Match(
-                scrutinee,
-                [
-                    arm,
-                    Arm {
-                        pat: Pat {
-                            kind: PatKind::Wild,
-                            ..
-                        },
+            && let ExprKind::Match(scrutinee, arms, MatchSource::Normal) = if_expr.kind
+            && let [
+                real_arms @ ..,
+                wild_arm @ Arm {
+                    pat: Pat {
+                        kind: PatKind::Wild,
                         ..
                     },
-                ],
-                MatchSource::Normal,
-            ) = if_expr.kind
+                    guard: None,
+                    ..
+                },
+            ] = arms
+            && !real_arms.is_empty()
+            // Each arm's body has to actually be the literal `true`/`false` that `matches!` itself
+            // would produce; otherwise the guard's truth value can depend on more than just which
+            // pattern matched, and folding the patterns together would change behavior.
+            && is_bool_lit(wild_arm.body, false)
+            && real_arms.iter().all(|arm| is_bool_lit(arm.body, true))
+            // A guard on just one alternative of an or-pattern only applies to that alternative,
+            // so it can't be hoisted out to guard the whole merged pattern unless there's just the
+            // one alternative to begin with.
+            && (real_arms.len() == 1 || real_arms.iter().all(|arm| arm.guard.is_none()))
+            // If the single real arm's own guard still refers to the scrutinee, hoisting it out
+            // is only sound when the outer pattern keeps binding that name; a folded pattern like
+            // `1` or `_` would leave the hoisted guard referring to a binding that's now gone (or,
+            // worse, silently fall back to an unrelated binding of the same name further out).
+            && real_arms[0]
+                .guard
+                .map_or(true, |g| !path_to_local(scrutinee).is_some_and(|local| guard_uses_local(cx, local, g)))
         {
-            emit_redundant_guards(
-                cx,
-                outer_arm,
-                if_expr.span,
-                scrutinee,
-                arm.pat.span,
-                arm.guard,
-            );
+            let mut app = Applicability::MaybeIncorrect;
+            let pat_text = real_arms
+                .iter()
+                .map(|arm| snippet_with_applicability(cx, arm.pat.span, "<binding_repl>", &mut app).into_owned())
+                .collect::<Vec<_>>()
+                .join(" | ");
+            let inner_guard = real_arms[0].guard;
+            emit_redundant_guards(cx, outer_arm, if_expr.span, scrutinee, &pat_text, app, inner_guard);
         }
         // `Some(x) if let Some(2) = x`
         else if let Guard::IfLet(let_expr) = guard {
-            emit_redundant_guards(
-                cx,
-                outer_arm,
-                let_expr.span,
-                let_expr.init,
-                let_expr.pat.span,
-                None,
-            );
+            let mut app = Applicability::MaybeIncorrect;
+            let pat_text = snippet_with_applicability(cx, let_expr.pat.span, "<binding_repl>", &mut app);
+            emit_redundant_guards(cx, outer_arm, let_expr.span, let_expr.init, &pat_text, app, None);
         }
-        // `Some(x) if x == Some(2)`
+        // `Some(x) if x == Some(2)`, or `Some(x) if x == [1, 2, 3]` for `x: &[i32]`
         else if let Guard::If(if_expr) = guard
             && let ExprKind::Binary(bin_op, local, pat) = if_expr.kind
             && matches!(bin_op.node, BinOpKind::Eq)
@@ -66,61 +82,308 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'tcx>]) {
             // extraordinary amount of FPs.
             //
             // This isn't necessary in the other two checks, as they must be a pattern already.
-            && cx.typeck_results().expr_ty(local) == cx.typeck_results().expr_ty(pat)
+            //
+            // The one exception is comparing a slice reference against an array literal: `&[i32]`
+            // and `[i32; 3]` are never the same type, even though `==` between them (via a blanket
+            // `PartialEq` impl) behaves exactly like the slice pattern would.
+            && (cx.typeck_results().expr_ty(local) == cx.typeck_results().expr_ty(pat)
+                || slice_like_array_eq(cx, local, pat))
+        {
+            let mut app = Applicability::MaybeIncorrect;
+            let pat_text = snippet_with_applicability(cx, pat.span, "<binding_repl>", &mut app);
+            emit_redundant_guards(cx, outer_arm, if_expr.span, local, &pat_text, app, None);
+        }
+        // `Some(x) if x >= 1 && x <= 5`, `Some(x) if x < 10`, or `Some(x) if (1..=5).contains(&x)`
+        else if let Guard::If(if_expr) = guard
+            && let Some((local, pat_text, app)) = check_range_guard(cx, if_expr)
+        {
+            emit_redundant_guards(cx, outer_arm, if_expr.span, local, &pat_text, app, None);
+        }
+        // `Some(x) if x.is_empty()`, for `x` a slice or `&str`
+        else if let Guard::If(if_expr) = guard
+            && let ExprKind::MethodCall(seg, recv, [], _) = if_expr.kind
+            && seg.ident.name.as_str() == "is_empty"
+            && let Some(pat_text) = empty_collection_pat(cx, recv)
         {
             emit_redundant_guards(
                 cx,
                 outer_arm,
                 if_expr.span,
-                local,
-                pat.span,
+                recv,
+                pat_text,
+                Applicability::MaybeIncorrect,
                 None,
             );
         }
     }
 }
 
-fn get_pat_binding<'tcx>(cx: &LateContext<'tcx>, guard_expr: &Expr<'_>, outer_arm: &Arm<'tcx>) -> Option<(Span, bool)> {
-    if let Some(local) = path_to_local(guard_expr) && !is_local_used(cx, outer_arm.body, local) {
-        let mut span = None;
-        let mut multiple_bindings = false;
-        // `each_binding` gives the `HirId` of the `Pat` itself, not the binding
-        outer_arm.pat.walk(|pat| {
-            if let PatKind::Binding(_, hir_id, _, _) = pat.kind
-                && hir_id == local
-                && span.replace(pat.span).is_some()
-            {
-                multiple_bindings = true;
-                return false;
-            }
+/// Checks whether `guard` refers to `local`. Used to avoid hoisting a single real arm's guard out
+/// to cover the whole folded pattern when doing so would leave it referring to a binding that the
+/// fold just replaced.
+fn guard_uses_local(cx: &LateContext<'_>, local: HirId, guard: Guard<'_>) -> bool {
+    match guard {
+        Guard::If(e) => is_local_used(cx, e, local),
+        Guard::IfLet(l) => is_local_used(cx, l.init, local),
+    }
+}
 
-            true
-        });
+/// Reads `expr` as a literal integer, looking through a leading unary negation (`-5`). Returns
+/// `None` for anything else, including consts we can't evaluate without more context.
+fn as_literal_int(expr: &Expr<'_>) -> Option<i128> {
+    if let ExprKind::Unary(UnOp::Neg, inner) = expr.kind {
+        return as_literal_int(inner).map(|n| -n);
+    }
+    if let ExprKind::Lit(lit) = expr.kind
+        && let LitKind::Int(val, _) = lit.node
+    {
+        return i128::try_from(val).ok();
+    }
+    None
+}
 
-        // Ignore bindings from or patterns, like `First(x) | Second(x, _) | Third(x, _, _)`
-        if !multiple_bindings {
-            return span.map(|span| {
-                (
-                    span,
-                    !matches!(cx.tcx.hir().get_parent(local), Node::PatField(_)),
-                )
-            });
+/// Checks that `expr` is literally the boolean literal `val`. Used to make sure a `match`-as-guard
+/// arm's body is exactly what `matches!` itself would generate, since folding arms together is
+/// only sound when each arm's truth value comes purely from which pattern matched.
+fn is_bool_lit(expr: &Expr<'_>, val: bool) -> bool {
+    matches!(expr.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Bool(b) if b == val))
+}
+
+/// A one-sided bound on a local, e.g. the `x >= 1` in `x >= 1 && x <= 5`.
+struct RangeBound<'hir> {
+    local: &'hir Expr<'hir>,
+    bound: &'hir Expr<'hir>,
+    /// Whether the bound includes its endpoint (`>=`/`<=`) or not (`>`/`<`).
+    inclusive: bool,
+    /// Whether this is a lower bound (`x >= 1`) or an upper bound (`x <= 5`).
+    is_lower: bool,
+}
+
+/// Interprets `local OP bound` as a [`RangeBound`], if `op` is a comparison operator.
+fn as_range_bound<'hir>(op: BinOpKind, local: &'hir Expr<'hir>, bound: &'hir Expr<'hir>) -> Option<RangeBound<'hir>> {
+    let (is_lower, inclusive) = match op {
+        BinOpKind::Ge => (true, true),
+        BinOpKind::Gt => (true, false),
+        BinOpKind::Le => (false, true),
+        BinOpKind::Lt => (false, false),
+        _ => return None,
+    };
+
+    Some(RangeBound {
+        local,
+        bound,
+        inclusive,
+        is_lower,
+    })
+}
+
+/// Checks whether `if_expr` bounds a local into a range, so that it can be linted as a range
+/// pattern instead, handling the three shapes this can take:
+/// * A two-sided bound, e.g. `x >= 1 && x <= 5`, which becomes `1..=5`.
+/// * A one-sided bound, e.g. `x < 10`, which becomes `..10`.
+/// * A `Range::contains`-style call, e.g. `(1..=5).contains(&x)`, whose bounds are reused as-is.
+///
+/// Returns the local being matched on, along with the text of the equivalent range pattern.
+fn check_range_guard<'tcx>(
+    cx: &LateContext<'tcx>,
+    if_expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, String, Applicability)> {
+    let mut app = Applicability::MaybeIncorrect;
+
+    // `x >= 1 && x <= 5`
+    if let ExprKind::Binary(op, lhs, rhs) = if_expr.kind
+        && op.node == BinOpKind::And
+        && let ExprKind::Binary(lop, l_local, l_bound) = lhs.kind
+        && let ExprKind::Binary(rop, r_local, r_bound) = rhs.kind
+        && let Some(local_id) = path_to_local(l_local)
+        && path_to_local(r_local) == Some(local_id)
+        && expr_can_be_pat(cx, l_bound)
+        && expr_can_be_pat(cx, r_bound)
+        && let (Some(b1), Some(b2)) = (
+            as_range_bound(lop.node, l_local, l_bound),
+            as_range_bound(rop.node, r_local, r_bound),
+        )
+    {
+        let (lower, upper) = if b1.is_lower && !b2.is_lower {
+            (b1, b2)
+        } else if b2.is_lower && !b1.is_lower {
+            (b2, b1)
+        } else {
+            return None;
+        };
+
+        // There's no pattern for an exclusive lower bound (`1<..=5`), and an exclusive upper
+        // bound (`1..5`) requires the unstable `exclusive_range_pattern` feature.
+        if !lower.inclusive || (!upper.inclusive && !cx.tcx.features().exclusive_range_pattern) {
+            return None;
+        }
+
+        // If both bounds are plain integer literals we can check they're the right way around;
+        // `x >= 10 && x <= 1` would otherwise suggest the empty, non-compiling range `10..=1`. We
+        // can't check this for more complex consts, so just leave those be.
+        if let (Some(lo), Some(hi)) = (as_literal_int(lower.bound), as_literal_int(upper.bound))
+            && lo > hi
+        {
+            return None;
         }
+
+        let lo = snippet_with_applicability(cx, lower.bound.span, "<lower>", &mut app);
+        let hi = snippet_with_applicability(cx, upper.bound.span, "<upper>", &mut app);
+        let sep = if upper.inclusive { "..=" } else { ".." };
+        return Some((lower.local, format!("{lo}{sep}{hi}"), app));
+    }
+
+    // `x >= 1`, `x <= 5`, `x < 10` (there's no pattern for a bare exclusive lower bound like
+    // `x > 1`)
+    if let ExprKind::Binary(op, local, bound) = if_expr.kind
+        && let Some(b) = as_range_bound(op.node, local, bound)
+        && expr_can_be_pat(cx, b.bound)
+        && (b.inclusive || cx.tcx.features().exclusive_range_pattern)
+    {
+        let val = snippet_with_applicability(cx, b.bound.span, "<bound>", &mut app);
+        let pat_text = match (b.is_lower, b.inclusive) {
+            (true, true) => format!("{val}.."),
+            (false, true) => format!("..={val}"),
+            (false, false) => format!("..{val}"),
+            (true, false) => return None,
+        };
+        return Some((b.local, pat_text, app));
+    }
+
+    // `(1..=5).contains(&x)`
+    if let ExprKind::MethodCall(seg, receiver, [arg], _) = if_expr.kind
+        && seg.ident.name.as_str() == "contains"
+        && let Some(range) = clippy_utils::higher::Range::hir(receiver)
+        && range.start.map_or(true, |start| expr_can_be_pat(cx, start))
+        && range.end.map_or(true, |end| expr_can_be_pat(cx, end))
+        && let ExprKind::AddrOf(_, _, local) = arg.kind
+    {
+        // The receiver's span includes its parentheses, so the pattern is rebuilt from the bounds.
+        let start = range
+            .start
+            .map(|start| snippet_with_applicability(cx, start.span, "<start>", &mut app))
+            .unwrap_or_default();
+        let end = range
+            .end
+            .map(|end| snippet_with_applicability(cx, end.span, "<end>", &mut app))
+            .unwrap_or_default();
+        let sep = if matches!(range.limits, rustc_ast::RangeLimits::Closed) { "..=" } else { ".." };
+        return Some((local, format!("{start}{sep}{end}"), app));
     }
 
     None
 }
 
+/// Whether `local == pat` is a slice reference compared against an array literal of the same
+/// element type, e.g. `x == [1, 2, 3]` for `x: &[i32]`. Their types are never literally equal
+/// (`&[i32]` vs `[i32; 3]`), but the comparison still behaves exactly like the slice pattern would,
+/// unlike the `String`/`Vec<T>` deref-coercion shapes the type-equality check above has to reject.
+fn slice_like_array_eq<'tcx>(cx: &LateContext<'tcx>, local: &Expr<'_>, pat: &Expr<'_>) -> bool {
+    let ExprKind::Array(..) = pat.kind else {
+        return false;
+    };
+    let TyKind::Slice(local_elem_ty) = cx.typeck_results().expr_ty(local).peel_refs().kind() else {
+        return false;
+    };
+    let TyKind::Array(pat_elem_ty, _) = cx.typeck_results().expr_ty(pat).kind() else {
+        return false;
+    };
+    local_elem_ty == pat_elem_ty
+}
+
+/// Returns the empty pattern (`[]` or `""`) that `recv.is_empty()` can be rewritten to, or `None`
+/// if `recv` isn't directly a slice or `&str`.
+///
+/// We deliberately look at `recv`'s own type rather than peeling it through `Deref` (e.g. for a
+/// `String` or `Vec<T>` receiver): `is_empty` on those resolves through autoderef, but `[]`/`""`
+/// are patterns for `[T]`/`str` themselves, not for the smart pointer wrapping them, so suggesting
+/// them there would be the same deref-coercion mismatch the `==` check above has to avoid.
+fn empty_collection_pat(cx: &LateContext<'_>, recv: &Expr<'_>) -> Option<&'static str> {
+    let ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    if ty.is_slice() {
+        Some("[]")
+    } else if ty.is_str() {
+        Some("\"\"")
+    } else {
+        None
+    }
+}
+
+/// Recursively collects every binding of `local` found in `pat`, alongside whether *that specific*
+/// occurrence can be replaced in shorthand (`x` becomes `2`) or needs an explicit field name
+/// (`x` becomes `x: 2`). This has to be tracked per occurrence, rather than once for the whole
+/// pattern, because an or-pattern can legally bind the same name from a tuple-variant position in
+/// one alternative and a struct-variant field in another, e.g. `First(x) | Second { x }`.
+fn collect_pat_bindings<'tcx>(pat: &Pat<'tcx>, local: HirId, in_shorthand_field: bool, out: &mut Vec<(Span, bool)>) {
+    match pat.kind {
+        PatKind::Binding(_, hir_id, _, subpat) => {
+            if hir_id == local {
+                out.push((pat.span, !in_shorthand_field));
+            }
+            if let Some(subpat) = subpat {
+                collect_pat_bindings(subpat, local, in_shorthand_field, out);
+            }
+        },
+        PatKind::Struct(_, fields, _) => {
+            for field in fields {
+                collect_pat_bindings(field.pat, local, field.is_shorthand, out);
+            }
+        },
+        PatKind::TupleStruct(_, pats, _) | PatKind::Tuple(pats, _) => {
+            for pat in pats {
+                collect_pat_bindings(pat, local, false, out);
+            }
+        },
+        PatKind::Slice(prefix, rest, suffix) => {
+            for pat in prefix {
+                collect_pat_bindings(pat, local, false, out);
+            }
+            if let Some(rest) = rest {
+                collect_pat_bindings(rest, local, false, out);
+            }
+            for pat in suffix {
+                collect_pat_bindings(pat, local, false, out);
+            }
+        },
+        PatKind::Or(pats) => {
+            for pat in pats {
+                collect_pat_bindings(pat, local, in_shorthand_field, out);
+            }
+        },
+        PatKind::Box(pat) | PatKind::Ref(pat, _) => collect_pat_bindings(pat, local, in_shorthand_field, out),
+        _ => {},
+    }
+}
+
+/// Finds every span binding `guard_expr`'s underlying local in `outer_arm`'s pattern, along with
+/// whether each can be replaced in shorthand. Usually there's only one, but an or-pattern can bind
+/// the same name at more than one alternative, e.g. `First(x) | Second(x, _)`, in which case all of
+/// them need to be rewritten together.
+fn get_pat_binding<'tcx>(
+    cx: &LateContext<'tcx>,
+    guard_expr: &Expr<'_>,
+    outer_arm: &Arm<'tcx>,
+) -> Option<Vec<(Span, bool)>> {
+    let local = path_to_local(guard_expr)?;
+    if is_local_used(cx, outer_arm.body, local) {
+        return None;
+    }
+
+    let mut bindings = Vec::new();
+    collect_pat_bindings(outer_arm.pat, local, false, &mut bindings);
+    (!bindings.is_empty()).then_some(bindings)
+}
+
 fn emit_redundant_guards<'tcx>(
     cx: &LateContext<'tcx>,
     outer_arm: &Arm<'tcx>,
     guard_span: Span,
     local: &Expr<'_>,
-    pat_span: Span,
+    pat_text: &str,
+    mut app: Applicability,
     inner_guard: Option<Guard<'_>>,
 ) {
-    let mut app = Applicability::MaybeIncorrect;
-    let Some((pat_binding, can_use_shorthand)) = get_pat_binding(cx, local, outer_arm) else {
+    let Some(pat_bindings) = get_pat_binding(cx, local, outer_arm) else {
         return;
     };
 
@@ -130,32 +393,33 @@ fn emit_redundant_guards<'tcx>(
         guard_span.source_callsite(),
         "redundant guard",
         |diag| {
-            let binding_replacement = snippet_with_applicability(cx, pat_span, "<binding_repl>", &mut app);
-            diag.multipart_suggestion_verbose(
-                "try",
-                vec![
+            let mut suggestions: Vec<(Span, String)> = pat_bindings
+                .into_iter()
+                .map(|(pat_binding, can_use_shorthand)| {
                     if can_use_shorthand {
-                        (pat_binding, binding_replacement.into_owned())
+                        (pat_binding, pat_text.to_owned())
                     } else {
-                        (pat_binding.shrink_to_hi(), format!(": {binding_replacement}"))
-                    },
-                    (
-                        guard_span.source_callsite().with_lo(outer_arm.pat.span.hi()),
-                        inner_guard.map_or_else(String::new, |guard| {
-                            let (prefix, span) = match guard {
-                                Guard::If(e) => ("if", e.span),
-                                Guard::IfLet(l) => ("if let", l.span),
-                            };
-
-                            format!(
-                                " {prefix} {}",
-                                snippet_with_applicability(cx, span, "<guard>", &mut app),
-                            )
-                        }),
-                    ),
-                ],
-                app,
-            );
+                        (pat_binding.shrink_to_hi(), format!(": {pat_text}"))
+                    }
+                })
+                .collect();
+
+            suggestions.push((
+                guard_span.source_callsite().with_lo(outer_arm.pat.span.hi()),
+                inner_guard.map_or_else(String::new, |guard| {
+                    let (prefix, span) = match guard {
+                        Guard::If(e) => ("if", e.span),
+                        Guard::IfLet(l) => ("if let", l.span),
+                    };
+
+                    format!(
+                        " {prefix} {}",
+                        snippet_with_applicability(cx, span, "<guard>", &mut app),
+                    )
+                }),
+            ));
+
+            diag.multipart_suggestion_verbose("try", suggestions, app);
         },
     );
 }
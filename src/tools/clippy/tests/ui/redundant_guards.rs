@@ -0,0 +1,149 @@
+#![allow(clippy::redundant_pattern_matching, dead_code)]
+#![warn(clippy::redundant_guards)]
+
+fn range_guards(x: i32) {
+    match Some(x) {
+        Some(x) if x >= 1 && x <= 5 => {},
+        _ => {},
+    }
+
+    match Some(x) {
+        Some(x) if x <= 5 => {},
+        _ => {},
+    }
+
+    match Some(x) {
+        Some(x) if x >= 1 => {},
+        _ => {},
+    }
+
+    match Some(x) {
+        Some(x) if (1..=5).contains(&x) => {},
+        _ => {},
+    }
+
+    // Not redundant: `x > 1` has no open-ended exclusive-lower-bound range pattern.
+    match Some(x) {
+        Some(x) if x > 1 => {},
+        _ => {},
+    }
+}
+
+fn is_empty_guards(s: &[i32], t: &str, owned: String) {
+    match Some(s) {
+        Some(x) if x.is_empty() => {},
+        _ => {},
+    }
+
+    match Some(t) {
+        Some(x) if x.is_empty() => {},
+        _ => {},
+    }
+
+    // Not redundant: `is_empty` here resolves through `Deref<Target = str>`, and there's no
+    // pattern for `String` itself.
+    match Some(&owned) {
+        Some(x) if x.is_empty() => {},
+        _ => {},
+    }
+}
+
+fn slice_eq_guards(s: &[i32], v: &Vec<i32>) {
+    match Some(s) {
+        Some(x) if x == [1, 2, 3] => {},
+        _ => {},
+    }
+
+    // Not redundant: `v`'s type only reaches `[i32]` through `Deref<Target = [i32]>`, and there's
+    // no pattern for `Vec<T>` itself.
+    match Some(v) {
+        Some(x) if *x == [1, 2, 3] => {},
+        _ => {},
+    }
+}
+
+fn or_pattern_same_shape() {
+    enum E {
+        First(i32),
+        Second(i32, i32),
+    }
+
+    match E::First(2) {
+        E::First(x) | E::Second(x, _) if x == 2 => {},
+        _ => {},
+    }
+}
+
+fn or_pattern_mixed_shape() {
+    enum E {
+        First(i32),
+        Second { x: i32 },
+    }
+
+    // Regression test: `x` is a positional binding in `First` but a struct field in `Second`, so
+    // each occurrence needs its own shorthand-vs-field-name replacement.
+    match E::First(2) {
+        E::First(x) | E::Second { x } if x == 2 => {},
+        _ => {},
+    }
+}
+
+fn matches_guards(x: i32) {
+    match Some(x) {
+        Some(x) if matches!(x, 1 | 2 | 3) => {},
+        _ => {},
+    }
+
+    match Some(x) {
+        Some(x) if matches!(x, y if y != 0) => {},
+        _ => {},
+    }
+
+    // Not redundant: the inner guard refers to `x`, the very binding the fold would replace with
+    // `1`, so hoisting it out would leave `x != 0` referring to a binding that's gone.
+    match Some(x) {
+        Some(x) if matches!(x, 1 if x != 0) => {},
+        _ => {},
+    }
+
+    // Hand-written equivalent of `matches!(x, 1 | 2 | 3)`, exercising the same multi-arm folding
+    // without going through the macro.
+    match Some(x) {
+        Some(x) if match x { 1 => true, 2 => true, 3 => true, _ => false } => {},
+        _ => {},
+    }
+
+    // Not redundant: the guard on `2` only applies to that alternative, so it can't be hoisted out
+    // to cover `1` as well.
+    match Some(x) {
+        Some(x) if match x { 1 => true, 2 if x != 0 => true, _ => false } => {},
+        _ => {},
+    }
+
+    // Not redundant: `2`'s body is `false`, so `x == 2` actually falls through to the outer
+    // wildcard arm, unlike `1`; folding the patterns together would change that.
+    match Some(x) {
+        Some(x) if match x { 1 => true, 2 => false, _ => false } => {},
+        _ => {},
+    }
+}
+
+fn range_guard_reversed_bounds(x: i32) {
+    // Not redundant: the bounds are the wrong way around (an always-false guard), so folding them
+    // into a pattern would suggest the non-compiling range `10..=1`.
+    match Some(x) {
+        Some(x) if x >= 10 && x <= 1 => {},
+        _ => {},
+    }
+}
+
+fn slice_rest_binding(s: [i32; 3]) {
+    // Regression test: `x` is bound in the slice's suffix, after a `..` rest pattern, not its
+    // prefix, so it has to be found there too.
+    match s {
+        [.., x] if x == 2 => {},
+        _ => {},
+    }
+}
+
+fn main() {}